@@ -3,6 +3,7 @@
 mod rgb;
 
 pub use self::rgb::RGB;
+use std::path::Path;
 use std::str::FromStr;
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// Mode is the color mode the program runs in. Corresponds to [Opt][crate::Opt] `--color`
@@ -26,28 +27,28 @@ pub enum Mode {
 
 impl Mode {
     pub const CGA_PALETTE: Self = Mode::KnownPalette {
-        palette: &[
-            cga::BLACK,
-            cga::BLUE,
-            cga::GREEN,
-            cga::CYAN,
-            cga::RED,
-            cga::MAGENTA,
-            cga::BROWN,
-            cga::LIGHT_GRAY,
-            cga::GRAY,
-            cga::LIGHT_BLUE,
-            cga::LIGHT_GREEN,
-            cga::LIGHT_CYAN,
-            cga::LIGHT_RED,
-            cga::LIGHT_MAGENTA,
-            cga::YELLOW,
-            cga::WHITE,
-        ],
-        name: "CGA",
+        palette: cga::PALETTE,
+        name: "cga",
     };
 }
 
+/// The registry of built-in named color schemes, selectable via
+/// `--color="palette:NAME"` and listable with `--list-palettes`.
+pub static PALETTE_REGISTRY: &[(&str, &[RGB<u8>])] = &[
+    ("cga", cga::PALETTE),
+    ("grayscale", grayscale::PALETTE),
+    ("tango", tango::PALETTE),
+    ("solarized", solarized::PALETTE),
+];
+
+/// Looks up a built-in named color scheme by name (case-insensitive).
+fn known_palette(name: &str) -> Option<Mode> {
+    PALETTE_REGISTRY
+        .iter()
+        .find(|(registered_name, _)| registered_name.eq_ignore_ascii_case(name))
+        .map(|&(name, palette)| Mode::KnownPalette { palette, name })
+}
+
 pub mod cga {
     use crate::prelude::RGB;
     pub const BLACK: RGB<u8> = RGB(0x00, 0x00, 0x00);
@@ -81,6 +82,95 @@ pub mod cga {
     pub const YELLOW: RGB<u8> = RGB(0xFF, 0xFF, 0x55);
     /// the 24-bit rgb representation of [CGA::White]
     pub const WHITE: RGB<u8> = RGB(0xFF, 0xFF, 0xFF);
+
+    /// the sixteen CGA colors, in their conventional index order.
+    pub const PALETTE: &[RGB<u8>] = &[
+        BLACK,
+        BLUE,
+        GREEN,
+        CYAN,
+        RED,
+        MAGENTA,
+        BROWN,
+        LIGHT_GRAY,
+        GRAY,
+        LIGHT_BLUE,
+        LIGHT_GREEN,
+        LIGHT_CYAN,
+        LIGHT_RED,
+        LIGHT_MAGENTA,
+        YELLOW,
+        WHITE,
+    ];
+}
+
+/// A 16-step grayscale ramp from black to white.
+pub mod grayscale {
+    use crate::prelude::RGB;
+    pub const PALETTE: &[RGB<u8>] = &[
+        RGB(0x00, 0x00, 0x00),
+        RGB(0x11, 0x11, 0x11),
+        RGB(0x22, 0x22, 0x22),
+        RGB(0x33, 0x33, 0x33),
+        RGB(0x44, 0x44, 0x44),
+        RGB(0x55, 0x55, 0x55),
+        RGB(0x66, 0x66, 0x66),
+        RGB(0x77, 0x77, 0x77),
+        RGB(0x88, 0x88, 0x88),
+        RGB(0x99, 0x99, 0x99),
+        RGB(0xAA, 0xAA, 0xAA),
+        RGB(0xBB, 0xBB, 0xBB),
+        RGB(0xCC, 0xCC, 0xCC),
+        RGB(0xDD, 0xDD, 0xDD),
+        RGB(0xEE, 0xEE, 0xEE),
+        RGB(0xFF, 0xFF, 0xFF),
+    ];
+}
+
+/// the 16-color "Tango" palette used as the default terminal scheme by GNOME Terminal and friends.
+pub mod tango {
+    use crate::prelude::RGB;
+    pub const PALETTE: &[RGB<u8>] = &[
+        RGB(0x2e, 0x34, 0x36),
+        RGB(0xcc, 0x00, 0x00),
+        RGB(0x4e, 0x9a, 0x06),
+        RGB(0xc4, 0xa0, 0x00),
+        RGB(0x34, 0x65, 0xa4),
+        RGB(0x75, 0x50, 0x7b),
+        RGB(0x06, 0x98, 0x9a),
+        RGB(0xd3, 0xd7, 0xcf),
+        RGB(0x55, 0x57, 0x53),
+        RGB(0xef, 0x29, 0x29),
+        RGB(0x8a, 0xe2, 0x34),
+        RGB(0xfc, 0xe9, 0x4f),
+        RGB(0x72, 0x9f, 0xcf),
+        RGB(0xad, 0x7f, 0xa8),
+        RGB(0x34, 0xe2, 0xe2),
+        RGB(0xee, 0xee, 0xec),
+    ];
+}
+
+/// the 16-color "Solarized" palette.
+pub mod solarized {
+    use crate::prelude::RGB;
+    pub const PALETTE: &[RGB<u8>] = &[
+        RGB(0x07, 0x36, 0x42),
+        RGB(0xdc, 0x32, 0x2f),
+        RGB(0x85, 0x99, 0x00),
+        RGB(0xb5, 0x89, 0x00),
+        RGB(0x26, 0x8b, 0xd2),
+        RGB(0xd3, 0x36, 0x82),
+        RGB(0x2a, 0xa1, 0x98),
+        RGB(0xee, 0xe8, 0xd5),
+        RGB(0x00, 0x2b, 0x36),
+        RGB(0xcb, 0x4b, 0x16),
+        RGB(0x58, 0x6e, 0x75),
+        RGB(0x65, 0x7b, 0x83),
+        RGB(0x83, 0x94, 0x96),
+        RGB(0x6c, 0x71, 0xc4),
+        RGB(0x93, 0xa1, 0xa1),
+        RGB(0xfd, 0xf6, 0xe3),
+    ];
 }
 
 impl Default for Mode {
@@ -98,6 +188,8 @@ pub enum Error {
     BadPaletteColor(u32),
     /// Error parsing the palette as a hexidecimal unsigned integer
     CouldNotParsePalette(std::num::ParseIntError),
+    /// A `palette-file:` path that could not be read
+    CouldNotReadPaletteFile(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -115,6 +207,24 @@ impl std::fmt::Display for Mode {
 impl<'a> FromStr for Mode {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("palette-file:") {
+            return Ok(Mode::CustomPalette(load_palette_file(path)?));
+        }
+
+        if let Some(name) = s.strip_prefix("palette:") {
+            return known_palette(name).ok_or_else(|| Error::UnknownOption(s.to_string()));
+        }
+
+        if let Some(rest) = s.strip_prefix("intensify:") {
+            let base_palette = match rest.parse::<Mode>()? {
+                Mode::SingleColor(color) => vec![color],
+                Mode::CustomPalette(palette) => palette,
+                Mode::KnownPalette { palette, .. } => palette.to_vec(),
+                mode => return Err(Error::UnknownOption(format!("intensify:{}", mode))),
+            };
+            return Ok(Mode::CustomPalette(intensify(&base_palette)));
+        }
+
         Ok(match s.to_ascii_uppercase().as_ref() {
             "WHITE" | "BLACK" | "BW" => Mode::BlackAndWhite,
             "C" | "COLOR" => Mode::Color,
@@ -158,6 +268,9 @@ impl std::fmt::Display for Error {
             Error::CouldNotParsePalette(err) => {
                 writeln!(f, "could not parse specified palette: {}", err)
             }
+            Error::CouldNotReadPaletteFile(err) => {
+                writeln!(f, "could not read palette file: {}", err)
+            }
         }
     }
 }
@@ -177,6 +290,28 @@ fn test_parse() {
         ("blue", Ok(Mode::SingleColor(cga::BLUE))),
         ("LigHT_CYAN", Ok(Mode::SingleColor(cga::LIGHT_CYAN))),
         ("cga", Ok(Mode::CGA_PALETTE)),
+        (
+            "palette:cga",
+            Ok(Mode::KnownPalette {
+                palette: cga::PALETTE,
+                name: "cga",
+            }),
+        ),
+        (
+            "palette:tango",
+            Ok(Mode::KnownPalette {
+                palette: tango::PALETTE,
+                name: "tango",
+            }),
+        ),
+        (
+            "palette:unknown-scheme",
+            Err(Error::UnknownOption("palette:unknown-scheme".to_string())),
+        ),
+        (
+            "intensify:RED",
+            Ok(Mode::CustomPalette(vec![cga::RED, bright(cga::RED)])),
+        ),
         (GARBAGE, Err(Error::UnknownOption(GARBAGE.to_string()))),
         // (
         //     "0x1ffffff 0x123129",
@@ -188,6 +323,120 @@ fn test_parse() {
     }
 }
 
+/// Parses a single `0xRRGGBB`-style hexadecimal color token, rejecting
+/// values outside `0..=0xFF_FF_FF`.
+fn parse_hex_token(token: &str) -> Result<RGB<u8>, Error> {
+    let digits = token.trim_start_matches("0x").trim_start_matches("0X");
+    let n = u32::from_str_radix(digits, 16)?;
+    if n > 0xFF_FF_FF {
+        return Err(Error::BadPaletteColor(n));
+    }
+    Ok(RGB(
+        ((n >> 16) & 0xFF) as u8,
+        ((n >> 8) & 0xFF) as u8,
+        (n & 0xFF) as u8,
+    ))
+}
+
+/// Loads a palette from a text file of whitespace-separated `0xRRGGBB` color
+/// expressions, one or more per line; lines starting with `#` are comments
+/// and are skipped. Used by `--color="palette-file:path.txt"`.
+pub fn load_palette_file(path: impl AsRef<Path>) -> Result<Vec<RGB<u8>>, Error> {
+    let contents = std::fs::read_to_string(path.as_ref())
+        .map_err(|err| Error::CouldNotReadPaletteFile(err.to_string()))?;
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .flat_map(str::split_whitespace)
+        .map(parse_hex_token)
+        .collect()
+}
+
+#[test]
+fn test_load_palette_file() {
+    let dir = std::env::temp_dir();
+    let path = dir.join("dither_test_palette.txt");
+
+    std::fs::write(
+        &path,
+        "# a comment line\n0x000000 0xFF00FF\n\n0xffffff\n# another comment\n",
+    )
+    .unwrap();
+    assert_eq!(
+        load_palette_file(&path),
+        Ok(vec![
+            RGB(0x00, 0x00, 0x00),
+            RGB(0xFF, 0x00, 0xFF),
+            RGB(0xFF, 0xFF, 0xFF),
+        ])
+    );
+
+    std::fs::write(&path, "0x1ffffff\n").unwrap();
+    assert_eq!(
+        load_palette_file(&path),
+        Err(Error::BadPaletteColor(0x1_ff_ff_ff))
+    );
+
+    std::fs::write(&path, "not-a-color\n").unwrap();
+    assert!(matches!(
+        load_palette_file(&path),
+        Err(Error::CouldNotParsePalette(_))
+    ));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// Derives the CGA-style "bright" form of a color: the dim form shifted up by the fixed
+/// intensity step (dim components live in `{0x00, 0xAA}`, bright in `{0x55, 0xFF}`).
+pub fn bright(RGB(r, g, b): RGB<u8>) -> RGB<u8> {
+    RGB(
+        r.saturating_add(0x55),
+        g.saturating_add(0x55),
+        b.saturating_add(0x55),
+    )
+}
+
+/// Doubles a base palette by appending each color's [bright] variant, CGA-style, so an
+/// 8-color base yields a full 16-entry palette. Used by `--color="intensify:..."`.
+pub fn intensify(base: &[RGB<u8>]) -> Vec<RGB<u8>> {
+    let mut palette = base.to_vec();
+    palette.extend(base.iter().copied().map(bright));
+    palette
+}
+
+/// Maps each of the sixteen [cga::PALETTE] colors, in order, to its standard ANSI terminal
+/// color number (0-7 normal, 8-15 bright).
+pub const CGA_TO_ANSI: [u8; 16] = [0, 4, 2, 6, 1, 5, 3, 7, 8, 12, 10, 14, 9, 13, 11, 15];
+
+/// Finds the nearest of the 16 standard CGA colors to `color` and returns its ANSI terminal
+/// color number. Used by `--preview` to render a dithered image directly to the terminal.
+pub fn nearest_ansi_color(color: RGB<u8>) -> u8 {
+    let RGB(r0, g0, b0) = RGB::<f64>::from(color);
+    let (index, _) = cga::PALETTE
+        .iter()
+        .cloned()
+        .map(RGB::<f64>::from)
+        .map(|RGB(r1, g1, b1)| f64::abs(r0 - r1) + f64::abs(g0 - g1) + f64::abs(b0 - b1))
+        .enumerate()
+        .fold((0, std::f64::INFINITY), |best, cur| {
+            if cur.1 < best.1 {
+                cur
+            } else {
+                best
+            }
+        });
+    CGA_TO_ANSI[index]
+}
+
+#[test]
+fn test_nearest_ansi_color() {
+    for (index, &color) in cga::PALETTE.iter().enumerate() {
+        assert_eq!(nearest_ansi_color(color), CGA_TO_ANSI[index]);
+    }
+}
+
 pub fn quantize_palette(palette: &[RGB<u8>]) -> impl Fn(RGB<f64>) -> (RGB<f64>, RGB<f64>) {
     let palette = palette.to_vec();
     move |RGB(r0, g0, b0)| {
@@ -16,7 +16,7 @@ mod opts;
 mod tests;
 
 use self::{
-    color::{CGA, RGB},
+    color::RGB,
     dither::Ditherer,
     error::{Error, Result},
     img::Img,
@@ -29,6 +29,18 @@ fn main() -> Result<()> {
 }
 
 fn _main(opts: &Opt) -> Result<()> {
+    if opts.list_palettes {
+        for (name, palette) in color::PALETTE_REGISTRY {
+            let colors = palette
+                .iter()
+                .map(|color| format!("{:x}", color))
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!("{}: {}", name, colors);
+        }
+        return Ok(());
+    }
+
     if opts.verbose {
         eprintln!(
             concat!(
@@ -57,8 +69,10 @@ fn _main(opts: &Opt) -> Result<()> {
     }
     let quantize = create_quantize_n_bits_func(opts.bit_depth)?;
 
-    let output_img = match opts.color_mode {
-        color::Mode::CGA | color::Mode::CustomPalette { .. } if opts.bit_depth > 1 => {
+    let output_img = match &opts.color_mode {
+        color::Mode::KnownPalette { .. } | color::Mode::CustomPalette(_)
+            if opts.bit_depth > 1 =>
+        {
             return Err(Error::IncompatibleOptions);
         }
 
@@ -67,11 +81,6 @@ fn _main(opts: &Opt) -> Result<()> {
             .dither(img, RGB::map_across(quantize))
             .convert_with(|rgb| rgb.convert_with(clamp_f64_to_u8)),
 
-        color::Mode::CGA => opts
-            .ditherer
-            .dither(img, CGA::quantize)
-            .convert_with(|rgb| rgb.convert_with(clamp_f64_to_u8)),
-
         color::Mode::BlackAndWhite => {
             let bw_img = img.convert_with(|rgb| rgb.to_chroma_corrected_black_and_white());
             opts.ditherer
@@ -79,14 +88,13 @@ fn _main(opts: &Opt) -> Result<()> {
                 .convert_with(RGB::from_chroma_corrected_black_and_white)
         }
 
-        color::Mode::SingleColor(color) => {
+        color::Mode::SingleColor(front) => {
             if opts.verbose {
-                eprintln!("single_color mode: {}", color)
+                eprintln!("single_color mode: {}", front)
             }
-            let (front, _) = color::Mode::custom_palette_from_cga(color);
 
             let bw_img = img.convert_with(|rgb| rgb.to_chroma_corrected_black_and_white());
-            let RGB(r, g, b) = front;
+            let RGB(r, g, b) = *front;
             opts.ditherer
                 .dither(bw_img, quantize)
                 .convert_with(|x: f64| {
@@ -98,20 +106,30 @@ fn _main(opts: &Opt) -> Result<()> {
                 })
         }
 
-        color::Mode::CustomPalette { front, back } => {
+        color::Mode::KnownPalette { palette, name } => {
             if opts.verbose {
-                eprintln!("cutom palette: front: {:?}, back {:?} ", &front, &back);
+                eprintln!("dithering against known palette: {}", name);
             }
-            let bw_img = img.convert_with(|rgb| rgb.to_chroma_corrected_black_and_white());
             opts.ditherer
-                .dither(bw_img, quantize)
-                .convert_with(create_convert_quantized_to_palette_func(front, back))
+                .dither(img, color::quantize_palette(palette))
+                .convert_with(|rgb| rgb.convert_with(clamp_f64_to_u8))
+        }
+
+        color::Mode::CustomPalette(palette) => {
+            if opts.verbose {
+                eprintln!("dithering against custom palette: {:?}", palette);
+            }
+            opts.ditherer
+                .dither(img, color::quantize_palette(palette))
                 .convert_with(|rgb| rgb.convert_with(clamp_f64_to_u8))
         }
     };
     if opts.verbose {
         eprintln!("dithering complete.\nsaving...");
     }
+    if opts.preview {
+        print_ansi_preview(&output_img);
+    }
     output_img.save(opts.output_path().as_ref())?;
     if opts.verbose {
         eprintln!("program finished");
@@ -119,6 +137,35 @@ fn _main(opts: &Opt) -> Result<()> {
     Ok(())
 }
 
+/// Renders `img` to the terminal as ANSI escape codes: each pixel is mapped to the nearest of
+/// the 16 standard CGA colors via [color::nearest_ansi_color], downsampled to the terminal
+/// width (from `$COLUMNS`, falling back to 80 columns), and two vertically-adjacent pixels are
+/// packed into one cell using the upper-half-block (`▀`) trick.
+fn print_ansi_preview(img: &Img<RGB<u8>>) {
+    let term_width = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse::<u32>().ok())
+        .unwrap_or(80)
+        .min(img.width());
+    let x_step = f64::from(img.width()) / f64::from(term_width);
+
+    let mut row = 0;
+    while row < img.height() {
+        for col in 0..term_width {
+            let x = (f64::from(col) * x_step) as u32;
+            let top = img.get(x, row).copied().unwrap_or_default();
+            let bottom = img.get(x, row + 1).copied().unwrap_or(top);
+            print!(
+                "\x1b[38;5;{}m\x1b[48;5;{}m\u{2580}",
+                color::nearest_ansi_color(top),
+                color::nearest_ansi_color(bottom),
+            );
+        }
+        println!("\x1b[0m");
+        row += 2;
+    }
+}
+
 /// quantize to n bits
 /// ```
 /// # use dither::create_quantize_n_bits_func;
@@ -150,15 +197,6 @@ pub fn create_quantize_n_bits_func(n: u8) -> Result<impl FnMut(f64) -> (f64, f64
     }
 }
 
-fn create_convert_quantized_to_palette_func(
-    front: RGB<u8>,
-    back: RGB<u8>,
-) -> impl Fn(f64) -> RGB<f64> {
-    let front = RGB::<f64>::from(front) / 255.;
-    let back = RGB::<f64>::from(back) / 255.;
-    move |x: f64| front.clone() * x + (back.clone() * (255. - x))
-}
-
 pub fn clamp_f64_to_u8(n: f64) -> u8 {
     match n {
         n if n > 255.0 => 255,
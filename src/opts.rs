@@ -59,8 +59,28 @@ pub struct Opt {
     ///     - "WHITE"
     /// - ("0xYYYYYY 0xZZZZZZ") -> user specified 1bit user color palette; where the first is foreground in hexidecimal and the second is background.
     /// - "cga" -> sixteen-color CGA. ignores bit depth; causes error on bit depth > 1
+    /// - "palette-file:PATH" -> dither against an arbitrary palette loaded from a text file of
+    ///   whitespace-separated `0xRRGGBB` color expressions (`#`-prefixed lines are comments).
+    ///   ignores bit depth; causes error on bit depth > 1
+    /// - "palette:NAME" -> dither against a built-in named color scheme (see `--list-palettes`).
+    ///   ignores bit depth; causes error on bit depth > 1
+    /// - "intensify:MODE" -> take the base palette of a single-color or palette `MODE` and
+    ///   expand it CGA-style into a 16-color (or double-size) palette by appending each color's
+    ///   bright variant. e.g. "intensify:RED" dithers between dim and bright red.
+    ///   ignores bit depth; causes error on bit depth > 1
     #[structopt(short = "c", long = "color", default_value = "bw")]
     pub color_mode: color::Mode,
+
+    /// List every built-in named color scheme (selectable with `--color="palette:NAME"`)
+    /// along with its hex colors, then exit.
+    #[structopt(long = "list-palettes")]
+    pub list_palettes: bool,
+
+    /// Render the dithered result directly to the terminal as ANSI escape codes (in addition
+    /// to writing the output file), mapping each pixel to the nearest of the 16 standard CGA
+    /// colors and downsampling to the terminal width.
+    #[structopt(long = "preview")]
+    pub preview: bool,
 }
 
 impl Opt {